@@ -0,0 +1,74 @@
+//! Autofix support for relocating imports between runtime scope and an `if TYPE_CHECKING:` block.
+//!
+//! Relocating an import is more than a text splice: the statement being moved may carry leading
+//! or trailing comments that belong with it, but the edit that actually re-inserts the statement
+//! into the `TYPE_CHECKING` block (or synthesizes one) is generated elsewhere and gives us no way
+//! to weave extra text into it. So rather than re-emitting the statement ourselves -- which would
+//! just duplicate it -- we capture only the comments ahead of time, and insert them as their own
+//! edit immediately before wherever the statement edit lands.
+
+use ruff_python_ast::{Ranged, Stmt};
+use ruff_source_file::Locator;
+use ruff_text_size::{TextRange, TextSize};
+
+/// The comments surrounding an import statement, captured before the statement is removed so they
+/// can be re-inserted next to its new home instead of being dropped.
+#[derive(Debug, Clone, Default)]
+pub(super) struct EditableImport {
+    /// Leading (own-line) comments immediately preceding the statement, verbatim, one per line,
+    /// in source order.
+    leading_comments: Vec<String>,
+    /// A trailing end-of-line comment attached to the statement, if any (e.g. `# noqa`).
+    trailing_comment: Option<String>,
+}
+
+impl EditableImport {
+    /// Capture the comments surrounding `stmt` in `locator`.
+    pub(super) fn capture(stmt: &Stmt, locator: &Locator) -> Self {
+        let line_range = locator.full_lines_range(stmt.range());
+
+        // Walk backward from the statement's own line over any contiguous standalone comment
+        // lines immediately above it; this is the common case (`# comment` on the line before
+        // `import foo`), which a scan limited to the statement's own line range would never see.
+        let leading_text = locator.slice(TextRange::new(TextSize::new(0), line_range.start()));
+        let mut leading_comments: Vec<String> = leading_text
+            .lines()
+            .rev()
+            .take_while(|line| line.trim_start().starts_with('#'))
+            .map(|line| line.trim().to_string())
+            .collect();
+        leading_comments.reverse();
+
+        let trailing_comment = locator
+            .slice(TextRange::new(stmt.range().end(), line_range.end()))
+            .trim()
+            .strip_prefix('#')
+            .map(|comment| format!("#{comment}"));
+
+        Self {
+            leading_comments,
+            trailing_comment,
+        }
+    }
+
+    /// Returns `true` if the statement had any comments attached to it.
+    pub(super) fn has_comments(&self) -> bool {
+        !self.leading_comments.is_empty() || self.trailing_comment.is_some()
+    }
+
+    /// Render the captured comments as standalone lines, re-indented to `indent`, ready to be
+    /// inserted immediately before the import's new home.
+    ///
+    /// The trailing end-of-line comment is rendered as its own comment line too, since we don't
+    /// control the text of the (already-generated) edit it used to trail -- a nearby comment line
+    /// still preserves the information, even if it's no longer strictly "trailing".
+    pub(super) fn render(&self, indent: &str) -> String {
+        let mut rendered = String::new();
+        for line in self.leading_comments.iter().chain(self.trailing_comment.iter()) {
+            rendered.push_str(indent);
+            rendered.push_str(line);
+            rendered.push('\n');
+        }
+        rendered
+    }
+}
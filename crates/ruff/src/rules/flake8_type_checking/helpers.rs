@@ -2,7 +2,8 @@ use rustpython_parser::ast;
 
 use ruff_python_ast::call_path::from_qualified_name;
 use ruff_python_ast::helpers::map_callable;
-use ruff_python_semantic::{Binding, BindingKind, ScopeKind, SemanticModel};
+use ruff_python_ast::node::AnyNodeRef;
+use ruff_python_semantic::{Binding, BindingKind, SemanticModel};
 
 pub(crate) fn is_valid_runtime_import(binding: &Binding, semantic: &SemanticModel) -> bool {
     if matches!(
@@ -46,15 +47,16 @@ pub(crate) fn runtime_required(
 }
 
 fn runtime_required_base_class(base_classes: &[String], semantic: &SemanticModel) -> bool {
-    if let ScopeKind::Class(ast::StmtClassDef { bases, .. }) = &semantic.scope().kind {
-        for base in bases {
-            if let Some(call_path) = semantic.resolve_call_path(base) {
-                if base_classes
-                    .iter()
-                    .any(|base_class| from_qualified_name(base_class) == call_path)
-                {
-                    return true;
-                }
+    let Some(class_def) = enclosing_class(semantic) else {
+        return false;
+    };
+    for base in &class_def.bases {
+        if let Some(call_path) = semantic.resolve_call_path(base) {
+            if base_classes
+                .iter()
+                .any(|base_class| from_qualified_name(base_class) == call_path)
+            {
+                return true;
             }
         }
     }
@@ -62,18 +64,40 @@ fn runtime_required_base_class(base_classes: &[String], semantic: &SemanticModel
 }
 
 fn runtime_required_decorators(decorators: &[String], semantic: &SemanticModel) -> bool {
-    if let ScopeKind::Class(ast::StmtClassDef { decorator_list, .. }) = &semantic.scope().kind {
-        for decorator in decorator_list {
-            if let Some(call_path) = semantic.resolve_call_path(map_callable(&decorator.expression))
+    let Some(class_def) = enclosing_class(semantic) else {
+        return false;
+    };
+    for decorator in &class_def.decorator_list {
+        if let Some(call_path) = semantic.resolve_call_path(map_callable(&decorator.expression)) {
+            if decorators
+                .iter()
+                .any(|decorator| from_qualified_name(decorator) == call_path)
             {
-                if decorators
-                    .iter()
-                    .any(|decorator| from_qualified_name(decorator) == call_path)
-                {
-                    return true;
-                }
+                return true;
             }
         }
     }
     false
 }
+
+/// Return the nearest enclosing `class` statement for the current statement (or the current
+/// statement itself, if it is one), walking up through any enclosing statements (function
+/// definitions, `if` blocks, ...) in between.
+///
+/// The lookup follows the statement tree rather than `semantic.scope().kind`, so it finds a class
+/// defined inside a function or a conditional just as it finds one at module level. Only
+/// statements are walked: expression scopes such as comprehensions and lambdas are not part of
+/// the chain.
+fn enclosing_class<'a>(semantic: &'a SemanticModel) -> Option<&'a ast::StmtClassDef> {
+    let current = semantic.current_statement_id();
+
+    // The base list and decorators of a class are part of the `StmtClassDef` statement itself,
+    // so while they're visited the class is the current statement; check the current statement
+    // before walking up through its ancestors.
+    std::iter::once(semantic.stmts[current])
+        .chain(semantic.stmts.ancestors(current))
+        .find_map(|node| match node {
+            AnyNodeRef::StmtClassDef(class_def) => Some(class_def),
+            _ => None,
+        })
+}
@@ -1,17 +1,22 @@
 use anyhow::Result;
-use ruff_text_size::{TextLen, TextRange, TextSize};
+use ruff_python_trivia::{SimpleTokenKind, SimpleTokenizer};
+use ruff_text_size::TextRange;
 use rustc_hash::FxHashMap;
+use rustpython_parser::ast;
 
 use ruff_diagnostics::{AutofixKind, Diagnostic, DiagnosticKind, Edit, Fix, Violation};
 use ruff_macros::{derive_message_formats, violation};
-use ruff_python_codegen::Stylist;
-use ruff_python_semantic::{Binding, NodeId, ResolvedReferenceId, Scope};
+use ruff_python_ast::call_path::from_qualified_name;
+use ruff_python_ast::Ranged;
+use ruff_python_codegen::{Generator, Stylist};
+use ruff_python_semantic::{Binding, NodeId, ResolvedReferenceId, Scope, SemanticModel};
 use ruff_source_file::Locator;
 
 use crate::autofix;
 use crate::checkers::ast::Checker;
 use crate::codes::Rule;
 use crate::importer::StmtImports;
+use crate::rules::flake8_type_checking::fix::EditableImport;
 use crate::rules::isort::{categorize, ImportSection, ImportType};
 
 /// ## What it does
@@ -440,6 +445,19 @@ fn fix_imports(checker: &Checker, stmt_id: NodeId, imports: &[Import]) -> Result
         .min()
         .expect("Expected at least one import");
 
+    // If we're moving every name the statement imports, capture the comments attached to it
+    // before it's removed, so we can carry those comments along to the `TYPE_CHECKING` block
+    // instead of leaving them orphaned at the old site.
+    let moves_entire_statement = import_names(stmt).is_some_and(|names| {
+        names.len() == qualified_names.len() && names.iter().all(|name| {
+            qualified_names
+                .iter()
+                .any(|qualified_name| qualified_name.ends_with(name.as_str()))
+        })
+    });
+    let editable_import =
+        moves_entire_statement.then(|| EditableImport::capture(stmt, checker.locator()));
+
     // Step 1) Remove the import.
     let remove_import_edit = autofix::edits::remove_unused_imports(
         qualified_names.iter().copied(),
@@ -451,14 +469,37 @@ fn fix_imports(checker: &Checker, stmt_id: NodeId, imports: &[Import]) -> Result
     )?;
 
     // Step 2) Add the import to a `TYPE_CHECKING` block.
-    let add_import_edit = checker.importer().typing_import_edit(
-        &StmtImports {
-            stmt,
-            qualified_names,
-        },
-        at,
-        checker.semantic(),
-    )?;
+    let add_import_edits: Vec<Edit> = checker
+        .importer()
+        .typing_import_edit(
+            &StmtImports {
+                stmt,
+                qualified_names,
+            },
+            at,
+            checker.semantic(),
+        )?
+        .into_edits();
+
+    // Step 2b) If the moved statement had its own leading or trailing comments, re-attach them
+    // immediately ahead of wherever the statement itself was just re-inserted, so they travel
+    // with it instead of being dropped at the old site.
+    //
+    // `add_import_edits` isn't documented to be in any particular order, so rather than assuming
+    // `.first()` is the statement-placement edit, pick whichever edit starts earliest in the
+    // source: the comments belong immediately above the statement's new home, which is the
+    // earliest point any of these edits touch (a synthesized `if TYPE_CHECKING:` header, if one
+    // is generated, would itself land at or before that point, not after).
+    let preserve_comment_edits = editable_import
+        .as_ref()
+        .filter(|import| import.has_comments())
+        .zip(add_import_edits.iter().min_by_key(|edit| edit.start()))
+        .map(|(import, edit)| {
+            let indent = checker.stylist().indentation().as_str();
+            vec![Edit::insertion(import.render(indent), edit.start())]
+        })
+        .into_iter()
+        .flatten();
 
     // Step 3) Quote any runtime usages of the referenced symbol.
     let quote_reference_edits = imports.iter().flat_map(|Import { binding, .. }| {
@@ -469,6 +510,7 @@ fn fix_imports(checker: &Checker, stmt_id: NodeId, imports: &[Import]) -> Result
                     reference.range(),
                     checker.locator(),
                     checker.stylist(),
+                    checker.semantic(),
                 ))
             } else {
                 None
@@ -478,80 +520,215 @@ fn fix_imports(checker: &Checker, stmt_id: NodeId, imports: &[Import]) -> Result
 
     Ok(Fix::suggested_edits(
         remove_import_edit,
-        add_import_edit
-            .into_edits()
+        add_import_edits
             .into_iter()
+            .chain(preserve_comment_edits)
             .chain(quote_reference_edits),
     )
     .isolate(checker.isolation(parent)))
 }
 
+/// Return the names imported by an `import` or `from ... import` statement, as written before
+/// any `as` alias, if `stmt` is one.
+fn import_names(stmt: &ruff_python_ast::Stmt) -> Option<Vec<&str>> {
+    match stmt {
+        ruff_python_ast::Stmt::Import(ruff_python_ast::StmtImport { names, .. })
+        | ruff_python_ast::Stmt::ImportFrom(ruff_python_ast::StmtImportFrom { names, .. }) => {
+            Some(names.iter().map(|alias| alias.name.as_str()).collect())
+        }
+        _ => None,
+    }
+}
+
 /// Quote a type annotation.
 ///
 /// This requires more than wrapping the reference in quotes. For example:
 /// - When quoting `Series` in `Series[pd.Timestamp]`, we want `"Series[pd.Timestamp]"`.
 /// - When quoting `kubernetes` in `kubernetes.SecurityContext`, we want `"kubernetes.SecurityContext"`.
 /// - When quoting `Series` in `Series["pd.Timestamp"]`, we want `"Series[pd.Timestamp]"`.
-fn quote_annotation(range: TextRange, locator: &Locator, stylist: &Stylist) -> Edit {
-    // Expand the annotation to the end of the reference.
+fn quote_annotation(
+    range: TextRange,
+    locator: &Locator,
+    stylist: &Stylist,
+    semantic: &SemanticModel,
+) -> Edit {
+    let annotation_range = find_annotation_range(range, locator);
+
+    if let Some(edit) = try_quote_annotation(annotation_range, locator, stylist, semantic) {
+        return edit;
+    }
+
+    // Fall back to a verbatim wrap if the annotation doesn't parse as an expression (e.g. because
+    // it contains a syntax error some upstream check missed). This can be ugly, but is better
+    // than not quoting at all.
+    let quote = stylist.quote();
+    let annotation = locator.slice(annotation_range);
+    Edit::range_replacement(format!("{quote}{annotation}{quote}"), annotation_range)
+}
+
+/// Parse `annotation_range` as a full Python expression and re-render it through
+/// `ruff_python_codegen`, unquoting any `ForwardRef` string literals nested inside (e.g. the
+/// `"pd.Timestamp"` in `Series["pd.Timestamp"]`) so that they aren't double-quoted by the
+/// resulting edit. Returns `None` if the range doesn't parse as an expression, or if it contains
+/// a comment: the parser discards comments and the generator has nothing to re-emit them from, so
+/// re-rendering a multi-line subscript with an embedded comment (e.g. `Dict[\n    str,  # keys\n
+/// int,\n]`) would silently drop it. Falling back to the verbatim wrap at least keeps the comment
+/// in the output, even though the result is uglier.
+fn try_quote_annotation(
+    annotation_range: TextRange,
+    locator: &Locator,
+    stylist: &Stylist,
+    semantic: &SemanticModel,
+) -> Option<Edit> {
+    let source = locator.slice(annotation_range);
+    if source.contains('#') {
+        return None;
+    }
+
+    let mut expr = rustpython_parser::parse_expression(source, "<filename>").ok()?;
+
+    unquote_forward_refs(&mut expr, semantic);
+
+    let mut generator = Generator::new(stylist.indentation(), stylist.line_ending());
+    generator.unparse_expr(&expr, 0);
+    let annotation = generator.generate();
+
+    let quote = stylist.quote();
+    Some(Edit::range_replacement(
+        format!("{quote}{annotation}{quote}"),
+        annotation_range,
+    ))
+}
+
+/// Recursively replace any string-literal `ForwardRef`s nested inside `expr` (e.g. `"int"` in
+/// `Optional["int"]`) with the expression they spell out, so that quoting the outer annotation
+/// doesn't produce doubled-up quotes. Leaves the string as-is if it doesn't parse as an
+/// expression, since it might be a genuine string value rather than a forward reference.
+///
+/// Parsing alone can't distinguish a forward ref from an ordinary string *value* that happens to
+/// look like one (`Literal["foo"]`'s `"foo"` parses just as cleanly as a real forward ref would).
+/// So `Literal[...]`'s arguments are deliberately skipped -- every other subscript slice is
+/// assumed to hold type expressions, which is the only place forward refs are legal to begin
+/// with.
+fn unquote_forward_refs(expr: &mut ast::Expr, semantic: &SemanticModel) {
+    match expr {
+        ast::Expr::Constant(ast::ExprConstant {
+            value: ast::Constant::Str(value),
+            ..
+        }) => {
+            if let Ok(mut parsed) = rustpython_parser::parse_expression(value, "<filename>") {
+                unquote_forward_refs(&mut parsed, semantic);
+                *expr = parsed;
+            }
+        }
+        ast::Expr::Attribute(ast::ExprAttribute { value, .. }) => {
+            unquote_forward_refs(value, semantic);
+        }
+        ast::Expr::Subscript(ast::ExprSubscript { value, slice, .. }) => {
+            unquote_forward_refs(value, semantic);
+            if !is_literal_subscript(value, semantic) {
+                unquote_forward_refs(slice, semantic);
+            }
+        }
+        ast::Expr::Tuple(ast::ExprTuple { elts, .. })
+        | ast::Expr::List(ast::ExprList { elts, .. }) => {
+            for elt in elts {
+                unquote_forward_refs(elt, semantic);
+            }
+        }
+        ast::Expr::BinOp(ast::ExprBinOp { left, right, .. }) => {
+            unquote_forward_refs(left, semantic);
+            unquote_forward_refs(right, semantic);
+        }
+        _ => {}
+    }
+}
+
+/// Return `true` if `value` is the `Literal` in a `Literal[...]` subscript, whose arguments are
+/// string *values* rather than type expressions.
+fn is_literal_subscript(value: &ast::Expr, semantic: &SemanticModel) -> bool {
+    semantic
+        .resolve_call_path(value)
+        .is_some_and(|call_path| call_path == from_qualified_name("typing.Literal"))
+}
+
+/// Find the full extent of the annotation expression starting at `range`, expanding through the
+/// attribute chain that follows it and up to the end of the first subscript. Unlike a character-by-character scan, matching
+/// brackets via a real tokenizer means a subscript that happens to span multiple lines (e.g. a
+/// `Annotated[...]` call broken across lines) is still captured correctly.
+fn find_annotation_range(range: TextRange, locator: &Locator) -> TextRange {
+    let mut tokenizer =
+        SimpleTokenizer::starts_at(range.end(), locator.contents()).skip_trivia();
     let mut depth = 0u32;
-    let mut len = TextSize::default();
-    let mut annotation = String::with_capacity(range.len().into());
-    for c in locator.after(range.start()).chars() {
-        match c {
-            '[' => depth += 1,
-            ']' => {
-                // Ex) Quoting `int` in `DataFrame[int]`, which should expand until the end of the
-                // `int` symbol`.
+    let mut end = range.end();
+
+    while let Some(token) = tokenizer.next() {
+        match token.kind() {
+            SimpleTokenKind::LBracket => {
+                depth += 1;
+                end = token.end();
+            }
+            SimpleTokenKind::RBracket => {
                 if depth == 0 {
                     break;
                 }
-
                 depth -= 1;
-
-                // Ex) Quoting `DataFrame` in `DataFrame[int]`, which should expand until the end
-                // of the subscript.
+                end = token.end();
                 if depth == 0 {
-                    annotation.push(c);
-                    len += c.text_len();
                     break;
                 }
             }
-            '.' => {
-                // Expand attributes.
-            }
-            'a'..='z' | 'A'..='Z' | '_' | '0'..='9' => {
-                // Expand identifiers.
+            SimpleTokenKind::Dot | SimpleTokenKind::Name => {
+                end = token.end();
             }
-            '"' | '\'' => {
-                // Skip quotes.
-                // TODO(charlie): Retain escaped quotes, and quotes in literals.
-                len += c.text_len();
-                continue;
-            }
-            '\n' | '\r' if depth > 0 => {
-                // If we hit a newline, fallback to replacing the range. This can be ugly, but is
-                // better than not quoting at all.
-                let annotation = locator.slice(range);
-                let quote = stylist.quote();
-                let annotation = format!("{quote}{annotation}{quote}");
-                return Edit::range_replacement(annotation, range);
-            }
-            _ => {
-                // If we hit a space, or a parenthesis, or any other character (and we're not in
-                // a subscript), we're done.
-                if depth == 0 {
-                    break;
-                }
+            _ if depth > 0 => {
+                end = token.end();
             }
+            _ => break,
         }
-        annotation.push(c);
-        len += c.text_len();
     }
 
-    // Wrap in quotes.
-    let quote = stylist.quote();
-    let annotation = format!("{quote}{annotation}{quote}");
+    TextRange::new(range.start(), end)
+}
+
+#[cfg(test)]
+mod tests {
+    use ruff_source_file::Locator;
+    use ruff_text_size::{TextRange, TextSize};
+
+    use super::find_annotation_range;
+
+    fn find(source: &str, name_range: TextRange) -> &str {
+        let locator = Locator::new(source);
+        let range = find_annotation_range(name_range, &locator);
+        locator.slice(range)
+    }
 
-    Edit::range_replacement(annotation, TextRange::at(range.start(), len))
+    #[test]
+    fn bare_name() {
+        let source = "A";
+        let range = TextRange::new(TextSize::new(0), TextSize::new(1));
+        assert_eq!(find(source, range), "A");
+    }
+
+    #[test]
+    fn attribute_chain() {
+        let source = "kubernetes.SecurityContext";
+        let range = TextRange::new(TextSize::new(0), TextSize::new(10));
+        assert_eq!(find(source, range), source);
+    }
+
+    #[test]
+    fn subscripted() {
+        let source = "Series[pd.Timestamp]";
+        let range = TextRange::new(TextSize::new(0), TextSize::new(6));
+        assert_eq!(find(source, range), source);
+    }
+
+    #[test]
+    fn subscript_spanning_multiple_lines() {
+        let source = "Annotated[\n    int,\n    Field(),\n]";
+        let range = TextRange::new(TextSize::new(0), TextSize::new(9));
+        assert_eq!(find(source, range), source);
+    }
 }
@@ -4,46 +4,64 @@ use ruff_text_size::TextRange;
 use crate::node::AnyNodeRef;
 use crate::Ranged;
 
-/// A wrapper around an expression that may be parenthesized.
+/// A wrapper around an expression that may be parenthesized, possibly by more than one
+/// concentric layer of parentheses (e.g. `((x))`).
 #[derive(Debug)]
 pub struct ParenthesizedExpression<'a> {
     /// The underlying AST node.
     expr: AnyNodeRef<'a>,
-    /// The range of the expression including parentheses, if the expression is parenthesized;
-    /// or `None`, if the expression is not parenthesized.
-    range: Option<TextRange>,
+    /// The range of each layer of parentheses wrapping the expression, from innermost to
+    /// outermost; or an empty vector, if the expression is not parenthesized.
+    layers: Vec<TextRange>,
 }
 
 impl<'a> ParenthesizedExpression<'a> {
     pub fn from_expr(expr: AnyNodeRef<'a>, contents: &str) -> Self {
         Self {
             expr,
-            range: parenthesized_range(expr, contents),
+            layers: parenthesized_ranges(expr, contents),
         }
     }
 
     /// Returns `true` if the expression is parenthesized.
     pub fn is_parenthesized(&self) -> bool {
-        self.range.is_some()
+        !self.layers.is_empty()
+    }
+
+    /// Returns the number of concentric parenthesization layers wrapping the expression.
+    pub fn depth(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns the range of the outermost parenthesized layer, if the expression is
+    /// parenthesized; or the range of the expression itself, otherwise.
+    pub fn outermost_range(&self) -> TextRange {
+        self.layers.last().copied().unwrap_or_else(|| self.expr.range())
+    }
+
+    /// Returns an iterator over each parenthesized layer's [`TextRange`], from innermost
+    /// (closest to the expression) to outermost.
+    pub fn layers(&self) -> impl DoubleEndedIterator<Item = TextRange> + '_ {
+        self.layers.iter().copied()
     }
 }
 
 impl Ranged for ParenthesizedExpression<'_> {
     fn range(&self) -> TextRange {
-        self.range.unwrap_or_else(|| self.expr.range())
+        self.outermost_range()
     }
 }
 
-/// Returns the [`TextRange`] of a given expression including parentheses, if the expression is
-/// parenthesized; or `None`, if the expression is not parenthesized.
-fn parenthesized_range(expr: AnyNodeRef, contents: &str) -> Option<TextRange> {
+/// Returns the [`TextRange`] of a given expression including one layer of parentheses, if the
+/// expression is parenthesized; or `None`, if the expression is not parenthesized.
+fn parenthesized_range(inner: TextRange, contents: &str) -> Option<TextRange> {
     // First, test if there's a closing parenthesis because it tends to be cheaper.
-    let right = first_non_trivia_token(expr.end(), contents)?;
+    let right = first_non_trivia_token(inner.end(), contents)?;
 
     if right.kind == SimpleTokenKind::RParen {
         // Next, test for the opening parenthesis.
         let mut tokenizer =
-            SimpleTokenizer::up_to_without_back_comment(expr.start(), contents).skip_trivia();
+            SimpleTokenizer::up_to_without_back_comment(inner.start(), contents).skip_trivia();
         let left = tokenizer.next_back()?;
         if left.kind == SimpleTokenKind::LParen {
             return Some(TextRange::new(left.start(), right.end()));
@@ -52,3 +70,23 @@ fn parenthesized_range(expr: AnyNodeRef, contents: &str) -> Option<TextRange> {
 
     None
 }
+
+/// Returns every concentric parenthesized [`TextRange`] wrapping a given expression, from
+/// innermost to outermost; or an empty vector, if the expression is not parenthesized at all.
+///
+/// Each layer is discovered by re-running the forward/backward [`SimpleTokenizer`] scan from
+/// just outside the previous layer's matching parentheses, so `((x))` yields two ranges: `(x)`
+/// and `((x))`.
+fn parenthesized_ranges(expr: AnyNodeRef, contents: &str) -> Vec<TextRange> {
+    let mut layers = Vec::new();
+    let mut inner = expr.range();
+
+    // The cheap "check for a closing paren first" short-circuit in `parenthesized_range` means
+    // an unparenthesized expression bails out on the first iteration, just as before.
+    while let Some(range) = parenthesized_range(inner, contents) {
+        layers.push(range);
+        inner = range;
+    }
+
+    layers
+}
@@ -1,11 +1,11 @@
-use ruff_formatter::{write, Buffer, FormatResult};
-use ruff_python_ast::MatchCase;
+use ruff_formatter::{write, FormatResult};
+use ruff_python_ast::{MatchCase, Pattern};
 
 use crate::comments::SourceComment;
-use crate::not_yet_implemented_custom_text;
+use crate::pattern::maybe_parenthesize_pattern;
 use crate::prelude::*;
 use crate::statement::clause::{clause_header, ClauseHeader};
-use crate::{FormatNodeRule, PyFormatter};
+use crate::{AsFormat, FormatNodeRule, PyFormatter};
 
 #[derive(Default)]
 pub struct FormatMatchCase;
@@ -29,26 +29,16 @@ impl FormatNodeRule<MatchCase> for FormatMatchCase {
                     ClauseHeader::MatchCase(item),
                     dangling_item_comments,
                     &format_with(|f| {
+                        // A multiline `MatchOr` needs explicit parentheses around it, since
+                        // splitting its alternatives across lines with no enclosing brackets
+                        // would be invalid syntax.
+                        let parenthesize = matches!(pattern, Pattern::MatchOr(_));
                         write!(
                             f,
                             [
                                 text("case"),
                                 space(),
-                                format_with(|f: &mut PyFormatter| {
-                                    let comments = f.context().comments();
-
-                                    for comment in comments.leading_trailing_comments(pattern) {
-                                        // This is a lie, but let's go with it.
-                                        comment.mark_formatted();
-                                    }
-
-                                    // Replace the whole `format_with` with `pattern.format()` once pattern formatting is implemented.
-                                    not_yet_implemented_custom_text(
-                                        "NOT_YET_IMPLEMENTED_Pattern",
-                                        pattern,
-                                    )
-                                    .fmt(f)
-                                }),
+                                maybe_parenthesize_pattern(pattern, parenthesize)
                             ]
                         )?;
 
@@ -0,0 +1,74 @@
+use ruff_formatter::{write, FormatResult};
+use ruff_python_ast::Pattern;
+
+use crate::context::PyFormatContext;
+use crate::prelude::*;
+use crate::{AsFormat, FormatNodeRule, PyFormatter};
+
+pub(crate) mod pattern_match_as;
+pub(crate) mod pattern_match_class;
+pub(crate) mod pattern_match_mapping;
+pub(crate) mod pattern_match_or;
+pub(crate) mod pattern_match_sequence;
+pub(crate) mod pattern_match_singleton;
+pub(crate) mod pattern_match_star;
+pub(crate) mod pattern_match_value;
+
+#[derive(Default)]
+pub struct FormatPattern;
+
+impl FormatNodeRule<Pattern> for FormatPattern {
+    fn fmt_fields(&self, item: &Pattern, f: &mut PyFormatter) -> FormatResult<()> {
+        match item {
+            Pattern::MatchValue(pattern) => pattern.format().fmt(f),
+            Pattern::MatchSingleton(pattern) => pattern.format().fmt(f),
+            Pattern::MatchSequence(pattern) => pattern.format().fmt(f),
+            Pattern::MatchMapping(pattern) => pattern.format().fmt(f),
+            Pattern::MatchClass(pattern) => pattern.format().fmt(f),
+            Pattern::MatchStar(pattern) => pattern.format().fmt(f),
+            Pattern::MatchAs(pattern) => pattern.format().fmt(f),
+            Pattern::MatchOr(pattern) => pattern.format().fmt(f),
+        }
+    }
+}
+
+/// Wraps a pattern in parentheses if the caller asks for it (e.g. for a `MatchOr`, which
+/// requires parentheses once it spans multiple lines) and the pattern doesn't fit on one line;
+/// a pattern that fits is written without parentheses.
+///
+/// Redundant parentheses that already exist around the *whole* pattern are removed: formatting
+/// always starts from the un-parenthesized `Pattern` node, so simply not re-emitting the source
+/// parentheses is sufficient to drop them.
+pub(crate) fn maybe_parenthesize_pattern<'a, 'ast>(
+    pattern: &'a Pattern,
+    parenthesize: bool,
+) -> FormatMaybeParenthesizedPattern<'a, 'ast> {
+    FormatMaybeParenthesizedPattern {
+        pattern,
+        parenthesize,
+        _marker: std::marker::PhantomData,
+    }
+}
+
+pub(crate) struct FormatMaybeParenthesizedPattern<'a, 'ast> {
+    pattern: &'a Pattern,
+    parenthesize: bool,
+    _marker: std::marker::PhantomData<&'ast ()>,
+}
+
+impl<'ast> Format<PyFormatContext<'ast>> for FormatMaybeParenthesizedPattern<'_, 'ast> {
+    fn fmt(&self, f: &mut Formatter<PyFormatContext<'ast>>) -> FormatResult<()> {
+        if self.parenthesize {
+            write!(
+                f,
+                [group(&format_args![
+                    if_group_breaks(&text("(")),
+                    soft_block_indent(&self.pattern.format()),
+                    if_group_breaks(&text(")")),
+                ])]
+            )
+        } else {
+            self.pattern.format().fmt(f)
+        }
+    }
+}
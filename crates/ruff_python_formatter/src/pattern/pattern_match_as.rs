@@ -0,0 +1,40 @@
+use ruff_formatter::{write, FormatResult};
+use ruff_python_ast::{Pattern, PatternMatchAs};
+
+use crate::pattern::maybe_parenthesize_pattern;
+use crate::prelude::*;
+use crate::{AsFormat, FormatNodeRule, PyFormatter};
+
+#[derive(Default)]
+pub struct FormatPatternMatchAs;
+
+impl FormatNodeRule<PatternMatchAs> for FormatPatternMatchAs {
+    fn fmt_fields(&self, item: &PatternMatchAs, f: &mut PyFormatter) -> FormatResult<()> {
+        let PatternMatchAs {
+            pattern,
+            name,
+            range: _,
+        } = item;
+
+        match (pattern, name) {
+            (Some(pattern), Some(name)) => {
+                // An `or`-pattern bound by an `as` is parenthesized if it breaks across lines,
+                // since its alternatives can't span lines without enclosing brackets.
+                let parenthesize = matches!(pattern.as_ref(), Pattern::MatchOr(_));
+                write!(
+                    f,
+                    [
+                        maybe_parenthesize_pattern(pattern, parenthesize),
+                        space(),
+                        text("as"),
+                        space(),
+                        name.format()
+                    ]
+                )
+            }
+            (Some(pattern), None) => write!(f, [pattern.format()]),
+            (None, Some(name)) => write!(f, [name.format()]),
+            (None, None) => write!(f, [text("_")]),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use ruff_formatter::{write, FormatResult};
+use ruff_python_ast::PatternMatchClass;
+
+use crate::prelude::*;
+use crate::{AsFormat, FormatNodeRule, PyFormatter};
+
+#[derive(Default)]
+pub struct FormatPatternMatchClass;
+
+impl FormatNodeRule<PatternMatchClass> for FormatPatternMatchClass {
+    fn fmt_fields(&self, item: &PatternMatchClass, f: &mut PyFormatter) -> FormatResult<()> {
+        let PatternMatchClass {
+            cls,
+            arguments,
+            range: _,
+        } = item;
+
+        write!(f, [cls.format()])?;
+
+        let patterns = &arguments.patterns;
+        let keywords = &arguments.keywords;
+
+        if patterns.is_empty() && keywords.is_empty() {
+            return write!(f, [text("("), text(")")]);
+        }
+
+        write!(
+            f,
+            [group(&format_args![
+                text("("),
+                soft_block_indent(&format_with(|f| {
+                    f.join_with(&format_args![text(","), soft_line_break_or_space()])
+                        .entries(patterns.iter().formatted())
+                        .finish()?;
+
+                    if !patterns.is_empty() && !keywords.is_empty() {
+                        write!(f, [text(","), soft_line_break_or_space()])?;
+                    }
+
+                    f.join_with(&format_args![text(","), soft_line_break_or_space()])
+                        .entries(keywords.iter().map(|keyword| {
+                            format_with(move |f| {
+                                write!(
+                                    f,
+                                    [
+                                        keyword.attr.format(),
+                                        text("="),
+                                        keyword.pattern.format()
+                                    ]
+                                )
+                            })
+                        }))
+                        .finish()?;
+
+                    write!(f, [trailing_comma()])
+                })),
+                text(")"),
+            ])]
+        )
+    }
+}
@@ -0,0 +1,47 @@
+use ruff_formatter::{write, FormatResult};
+use ruff_python_ast::PatternMatchMapping;
+
+use crate::prelude::*;
+use crate::{AsFormat, FormatNodeRule, PyFormatter};
+
+#[derive(Default)]
+pub struct FormatPatternMatchMapping;
+
+impl FormatNodeRule<PatternMatchMapping> for FormatPatternMatchMapping {
+    fn fmt_fields(&self, item: &PatternMatchMapping, f: &mut PyFormatter) -> FormatResult<()> {
+        let PatternMatchMapping {
+            keys,
+            patterns,
+            rest,
+            range: _,
+        } = item;
+
+        let entries = format_with(|f| {
+            f.join_with(&format_args![text(","), soft_line_break_or_space()])
+                .entries(keys.iter().zip(patterns.iter()).map(|(key, pattern)| {
+                    format_with(move |f| {
+                        write!(f, [key.format(), text(":"), space(), pattern.format()])
+                    })
+                }))
+                .finish()?;
+
+            if let Some(rest) = rest {
+                if !keys.is_empty() {
+                    write!(f, [text(","), soft_line_break_or_space()])?;
+                }
+                write!(f, [text("**"), rest.format()])?;
+            }
+
+            Ok(())
+        });
+
+        write!(
+            f,
+            [group(&format_args![
+                text("{"),
+                soft_block_indent(&format_args![entries, trailing_comma()]),
+                text("}"),
+            ])]
+        )
+    }
+}
@@ -0,0 +1,29 @@
+use ruff_formatter::{write, FormatResult};
+use ruff_python_ast::PatternMatchOr;
+
+use crate::prelude::*;
+use crate::{AsFormat, FormatNodeRule, PyFormatter};
+
+#[derive(Default)]
+pub struct FormatPatternMatchOr;
+
+impl FormatNodeRule<PatternMatchOr> for FormatPatternMatchOr {
+    fn fmt_fields(&self, item: &PatternMatchOr, f: &mut PyFormatter) -> FormatResult<()> {
+        let PatternMatchOr { patterns, range: _ } = item;
+
+        // Wrapped in a `group` so that, once the alternatives don't fit on a single line, each
+        // one gets its own continuation line, indented to align under the first.
+        write!(
+            f,
+            [group(&format_with(|f| {
+                f.join_with(&format_args![
+                    space(),
+                    text("|"),
+                    soft_line_break_or_space()
+                ])
+                .entries(patterns.iter().formatted())
+                .finish()
+            }))]
+        )
+    }
+}
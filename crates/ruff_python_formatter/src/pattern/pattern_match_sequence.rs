@@ -0,0 +1,180 @@
+use ruff_formatter::{write, FormatResult};
+use ruff_python_ast::{PatternMatchSequence, Ranged};
+use ruff_python_trivia::{SimpleTokenizer, SimpleTokenKind};
+
+use crate::prelude::*;
+use crate::{FormatNodeRule, PyFormatter};
+
+#[derive(Default)]
+pub struct FormatPatternMatchSequence;
+
+/// The bracket (if any) that opens a sequence pattern in the source.
+///
+/// `MatchSequence` is used for `[1, 2]`, `(1, 2)`, and the bare `1, 2` form (only legal as the
+/// subject-level pattern), and the AST doesn't retain which form was used, so we look at the
+/// first token to find out.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum SequenceBracket {
+    Square,
+    Paren,
+    None,
+}
+
+impl FormatNodeRule<PatternMatchSequence> for FormatPatternMatchSequence {
+    fn fmt_fields(&self, item: &PatternMatchSequence, f: &mut PyFormatter) -> FormatResult<()> {
+        let PatternMatchSequence { patterns, range } = item;
+
+        let bracket = sequence_bracket(item, f.context().source());
+
+        let (open, close) = match bracket {
+            SequenceBracket::Square => ("[", "]"),
+            // Normalize a bare `1, 2` subject pattern and a parenthesized `(1, 2)` pattern alike
+            // to use square brackets; all three forms match the same subjects.
+            SequenceBracket::Paren | SequenceBracket::None => ("[", "]"),
+        };
+
+        if patterns.is_empty() {
+            return write!(f, [text(open), text(close)]);
+        }
+
+        let magic_trailing_comma = has_magic_trailing_comma(*range, bracket, f.context().source());
+
+        write!(
+            f,
+            [group(&format_args![
+                text(open),
+                soft_block_indent(&format_with(|f| {
+                    f.join_with(&format_args![text(","), soft_line_break_or_space()])
+                        .entries(patterns.iter().formatted())
+                        .finish()?;
+
+                    if magic_trailing_comma {
+                        write!(f, [if_group_breaks(&text(","))])?;
+                    } else {
+                        write!(f, [trailing_comma()])?;
+                    }
+
+                    Ok(())
+                })),
+                text(close),
+            ])]
+        )
+    }
+}
+
+fn sequence_bracket(item: &PatternMatchSequence, source: &str) -> SequenceBracket {
+    let mut tokenizer = SimpleTokenizer::starts_at(item.range().start(), source).skip_trivia();
+    match tokenizer.next().map(|token| token.kind()) {
+        Some(SimpleTokenKind::LBracket) => SequenceBracket::Square,
+        Some(SimpleTokenKind::LParen) => SequenceBracket::Paren,
+        _ => SequenceBracket::None,
+    }
+}
+
+/// Returns `true` if the sequence pattern's source has a magic trailing comma, i.e. a comma
+/// immediately preceding the closing bracket, which forces the sequence to always be exploded.
+///
+/// The bare `case 1, 2,:` subject pattern has no closing bracket at all, so when `bracket` is
+/// [`SequenceBracket::None`] the trailing comma (if any) is simply the last token in `range`.
+fn has_magic_trailing_comma(
+    range: ruff_text_size::TextRange,
+    bracket: SequenceBracket,
+    source: &str,
+) -> bool {
+    let mut tokenizer =
+        SimpleTokenizer::up_to_without_back_comment(range.end(), source).skip_trivia();
+
+    if bracket == SequenceBracket::None {
+        return matches!(
+            tokenizer.next_back().map(|token| token.kind()),
+            Some(SimpleTokenKind::Comma)
+        );
+    }
+
+    let Some(last) = tokenizer.next_back() else {
+        return false;
+    };
+    if !matches!(
+        last.kind(),
+        SimpleTokenKind::RBracket | SimpleTokenKind::RParen
+    ) {
+        return false;
+    }
+    matches!(
+        tokenizer.next_back().map(|token| token.kind()),
+        Some(SimpleTokenKind::Comma)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use ruff_python_ast::PatternMatchSequence;
+    use ruff_text_size::{TextRange, TextSize};
+
+    use super::{has_magic_trailing_comma, sequence_bracket, SequenceBracket};
+
+    fn sequence_at(range: TextRange) -> PatternMatchSequence {
+        PatternMatchSequence {
+            range,
+            patterns: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn sequence_bracket_detects_square() {
+        let source = "case [1, 2]:";
+        let range = TextRange::new(TextSize::new(5), TextSize::new(11));
+        assert_eq!(
+            sequence_bracket(&sequence_at(range), source),
+            SequenceBracket::Square
+        );
+    }
+
+    #[test]
+    fn sequence_bracket_detects_paren() {
+        let source = "case (1, 2):";
+        let range = TextRange::new(TextSize::new(5), TextSize::new(11));
+        assert_eq!(
+            sequence_bracket(&sequence_at(range), source),
+            SequenceBracket::Paren
+        );
+    }
+
+    #[test]
+    fn sequence_bracket_detects_bare() {
+        let source = "case 1, 2:";
+        let range = TextRange::new(TextSize::new(5), TextSize::new(9));
+        assert_eq!(
+            sequence_bracket(&sequence_at(range), source),
+            SequenceBracket::None
+        );
+    }
+
+    #[test]
+    fn magic_trailing_comma_present() {
+        let source = "case [1, 2,]:";
+        let range = TextRange::new(TextSize::new(5), TextSize::new(12));
+        assert!(has_magic_trailing_comma(range, SequenceBracket::Square, source));
+    }
+
+    #[test]
+    fn magic_trailing_comma_absent() {
+        let source = "case [1, 2]:";
+        let range = TextRange::new(TextSize::new(5), TextSize::new(11));
+        assert!(!has_magic_trailing_comma(range, SequenceBracket::Square, source));
+    }
+
+    #[test]
+    fn magic_trailing_comma_present_in_bare_tuple() {
+        let source = "case 1, 2,:";
+        let range = TextRange::new(TextSize::new(5), TextSize::new(10));
+        assert!(has_magic_trailing_comma(range, SequenceBracket::None, source));
+    }
+
+    #[test]
+    fn magic_trailing_comma_absent_in_bare_tuple() {
+        let source = "case 1, 2:";
+        let range = TextRange::new(TextSize::new(5), TextSize::new(9));
+        assert!(!has_magic_trailing_comma(range, SequenceBracket::None, source));
+    }
+}
@@ -0,0 +1,22 @@
+use ruff_formatter::{write, FormatResult};
+use ruff_python_ast::{PatternMatchSingleton, Singleton};
+
+use crate::prelude::*;
+use crate::{FormatNodeRule, PyFormatter};
+
+#[derive(Default)]
+pub struct FormatPatternMatchSingleton;
+
+impl FormatNodeRule<PatternMatchSingleton> for FormatPatternMatchSingleton {
+    fn fmt_fields(&self, item: &PatternMatchSingleton, f: &mut PyFormatter) -> FormatResult<()> {
+        let PatternMatchSingleton { value, range: _ } = item;
+
+        let keyword = match value {
+            Singleton::None => "None",
+            Singleton::True => "True",
+            Singleton::False => "False",
+        };
+
+        write!(f, [text(keyword)])
+    }
+}
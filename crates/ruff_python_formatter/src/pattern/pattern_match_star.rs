@@ -0,0 +1,21 @@
+use ruff_formatter::{write, FormatResult};
+use ruff_python_ast::PatternMatchStar;
+
+use crate::prelude::*;
+use crate::{AsFormat, FormatNodeRule, PyFormatter};
+
+#[derive(Default)]
+pub struct FormatPatternMatchStar;
+
+impl FormatNodeRule<PatternMatchStar> for FormatPatternMatchStar {
+    fn fmt_fields(&self, item: &PatternMatchStar, f: &mut PyFormatter) -> FormatResult<()> {
+        let PatternMatchStar { name, range: _ } = item;
+
+        write!(f, [text("*")])?;
+
+        match name {
+            Some(name) => write!(f, [name.format()]),
+            None => write!(f, [text("_")]),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use ruff_formatter::{write, FormatResult};
+use ruff_python_ast::PatternMatchValue;
+
+use crate::prelude::*;
+use crate::{AsFormat, FormatNodeRule, PyFormatter};
+
+#[derive(Default)]
+pub struct FormatPatternMatchValue;
+
+impl FormatNodeRule<PatternMatchValue> for FormatPatternMatchValue {
+    fn fmt_fields(&self, item: &PatternMatchValue, f: &mut PyFormatter) -> FormatResult<()> {
+        let PatternMatchValue { value, range: _ } = item;
+
+        write!(f, [value.format()])
+    }
+}
@@ -9,7 +9,7 @@ use ruff_python_ast::{
     ElifElseClause, ExceptHandlerExceptHandler, MatchCase, Ranged, StmtClassDef, StmtFor,
     StmtFunctionDef, StmtIf, StmtMatch, StmtTry, StmtWhile, StmtWith,
 };
-use ruff_python_trivia::{SimpleToken, SimpleTokenKind, SimpleTokenizer};
+use ruff_python_trivia::{SimpleToken, SimpleTokenKind, SimpleTokenizer, T};
 use ruff_text_size::{TextRange, TextSize};
 
 /// The header of a compound statement clause.
@@ -141,29 +141,27 @@ impl<'a> ClauseHeader<'a> {
 
     fn keyword_range(self, source: &str) -> FormatResult<TextRange> {
         match self {
-            ClauseHeader::Class(header) => {
-                find_keyword(header.start(), SimpleTokenKind::Class, source)
-            }
+            ClauseHeader::Class(header) => find_keyword(header.start(), T![class], source),
             ClauseHeader::Function(header) => {
                 let keyword = if header.is_async {
-                    SimpleTokenKind::Async
+                    T![async]
                 } else {
-                    SimpleTokenKind::Def
+                    T![def]
                 };
                 find_keyword(header.start(), keyword, source)
             }
-            ClauseHeader::If(header) => find_keyword(header.start(), SimpleTokenKind::If, source),
+            ClauseHeader::If(header) => find_keyword(header.start(), T![if], source),
             ClauseHeader::ElifElse(ElifElseClause {
                 test: None, range, ..
-            }) => find_keyword(range.start(), SimpleTokenKind::Else, source),
+            }) => find_keyword(range.start(), T![else], source),
             ClauseHeader::ElifElse(ElifElseClause {
                 test: Some(_),
                 range,
                 ..
-            }) => find_keyword(range.start(), SimpleTokenKind::Elif, source),
-            ClauseHeader::Try(header) => find_keyword(header.start(), SimpleTokenKind::Try, source),
+            }) => find_keyword(range.start(), T![elif], source),
+            ClauseHeader::Try(header) => find_keyword(header.start(), T![try], source),
             ClauseHeader::ExceptHandler(header) => {
-                find_keyword(header.start(), SimpleTokenKind::Except, source)
+                find_keyword(header.start(), T![except], source)
             }
             ClauseHeader::TryFinally(header) => {
                 let last_statement = header
@@ -174,30 +172,24 @@ impl<'a> ClauseHeader<'a> {
                     .or_else(|| header.body.last().map(AnyNodeRef::from))
                     .unwrap();
 
-                find_keyword(last_statement.end(), SimpleTokenKind::Finally, source)
-            }
-            ClauseHeader::Match(header) => {
-                find_keyword(header.start(), SimpleTokenKind::Match, source)
-            }
-            ClauseHeader::MatchCase(header) => {
-                find_keyword(header.start(), SimpleTokenKind::Case, source)
+                find_keyword(last_statement.end(), T![finally], source)
             }
+            ClauseHeader::Match(header) => find_keyword(header.start(), T![match], source),
+            ClauseHeader::MatchCase(header) => find_keyword(header.start(), T![case], source),
             ClauseHeader::For(header) => {
                 let keyword = if header.is_async {
-                    SimpleTokenKind::Async
+                    T![async]
                 } else {
-                    SimpleTokenKind::For
+                    T![for]
                 };
                 find_keyword(header.start(), keyword, source)
             }
-            ClauseHeader::While(header) => {
-                find_keyword(header.start(), SimpleTokenKind::While, source)
-            }
+            ClauseHeader::While(header) => find_keyword(header.start(), T![while], source),
             ClauseHeader::With(header) => {
                 let keyword = if header.is_async {
-                    SimpleTokenKind::Async
+                    T![async]
                 } else {
-                    SimpleTokenKind::With
+                    T![with]
                 };
 
                 find_keyword(header.start(), keyword, source)
@@ -211,11 +203,11 @@ impl<'a> ClauseHeader<'a> {
                         .or_else(|| try_stmt.body.last().map(AnyNodeRef::from))
                         .unwrap();
 
-                    find_keyword(last_statement.end(), SimpleTokenKind::Else, source)
+                    find_keyword(last_statement.end(), T![else], source)
                 }
                 ElseClause::For(StmtFor { body, .. })
                 | ElseClause::While(StmtWhile { body, .. }) => {
-                    find_keyword(body.last().unwrap().end(), SimpleTokenKind::Else, source)
+                    find_keyword(body.last().unwrap().end(), T![else], source)
                 }
             },
         }
@@ -255,7 +247,7 @@ fn find_keyword(
 fn colon_range(after_keyword_or_condition: TextSize, source: &str) -> FormatResult<TextRange> {
     let mut tokenizer = SimpleTokenizer::starts_at(after_keyword_or_condition, source)
         .skip_trivia()
-        .skip_while(|token| token.kind() == SimpleTokenKind::RParen);
+        .skip_while(|token| token.kind() == T![')']);
 
     match tokenizer.next() {
         Some(SimpleToken {
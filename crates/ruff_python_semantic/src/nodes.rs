@@ -1,5 +1,8 @@
+use std::cell::OnceCell;
 use std::ops::Index;
 
+use rustc_hash::FxHashMap;
+
 use ruff_index::{newtype_index, IndexVec};
 use ruff_python_ast::node::AnyNodeRef;
 
@@ -12,25 +15,51 @@ use ruff_python_ast::node::AnyNodeRef;
 #[derive(Ord, PartialOrd)]
 pub struct NodeId;
 
-/// An AST node in a program, along with a pointer to its parent node (if any).
+/// An AST node in a program, along with a pointer to its parent node (if any) and its Euler-tour
+/// "enter" timestamp.
 #[derive(Debug)]
 struct NodeWithParent<'a> {
     /// A pointer to the AST node.
     node: AnyNodeRef<'a>,
     /// The ID of the parent of this node, if any.
     parent: Option<NodeId>,
+    /// The preorder index at which this node was inserted, i.e. the Euler-tour "enter" time.
+    enter: u32,
 }
 
 /// The nodes of a program indexed by [`NodeId`]
 #[derive(Debug, Default)]
 pub struct Nodes<'a> {
     nodes: IndexVec<NodeId, NodeWithParent<'a>>,
+    /// The child [`NodeId`]s of each node that has any, in insertion (i.e. source) order.
+    children: FxHashMap<NodeId, Vec<NodeId>>,
+    /// Each node's Euler-tour "exit" time, i.e. the `enter` time of the last-inserted node in its
+    /// subtree. Derived lazily from the parent links and `enter` times in `nodes` on first use,
+    /// rather than requiring the builder to stamp it explicitly on the way back up from its
+    /// depth-first walk: insertion only ever gives us a node's *preorder* position, so deriving
+    /// `exit` from the already-recorded parent links is both simpler and impossible to forget to
+    /// wire up.
+    exit: OnceCell<IndexVec<NodeId, u32>>,
 }
 
 impl<'a> Nodes<'a> {
     /// Inserts a new AST node into the tree and returns its unique ID.
+    ///
+    /// Nodes are expected to be inserted in the preorder of a depth-first AST walk, so the
+    /// insertion index doubles as the node's Euler-tour "enter" time.
     pub(crate) fn insert(&mut self, node: AnyNodeRef<'a>, parent: Option<NodeId>) -> NodeId {
-        self.nodes.push(NodeWithParent { node, parent })
+        let enter = self.nodes.len() as u32;
+        let node_id = self.nodes.push(NodeWithParent {
+            node,
+            parent,
+            enter,
+        });
+
+        if let Some(parent) = parent {
+            self.children.entry(parent).or_default().push(node_id);
+        }
+
+        node_id
     }
 
     /// Return the [`NodeId`] of the parent node.
@@ -43,6 +72,107 @@ impl<'a> Nodes<'a> {
     pub(crate) fn ancestor_ids(&self, node_id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
         std::iter::successors(Some(node_id), |&node_id| self.nodes[node_id].parent)
     }
+
+    /// Returns an iterator over the [`AnyNodeRef`] ancestors of a given [`NodeId`], starting
+    /// from (but not including) the node itself, and walking up through every enclosing
+    /// statement (function and class definitions, `if` blocks, ...) in between, up to and
+    /// including the module root.
+    ///
+    /// The iterator is lazy: each step only climbs one more parent link, so a caller that's
+    /// searching for the nearest ancestor of a particular kind (e.g. the nearest enclosing
+    /// `StmtClassDef`) via `.find_map(...)` never visits more of the tree than it has to.
+    pub fn ancestors(&self, node_id: NodeId) -> impl Iterator<Item = AnyNodeRef<'a>> + '_ {
+        self.ancestor_ids(node_id)
+            .skip(1)
+            .map(|node_id| self.nodes[node_id].node)
+    }
+
+    /// Returns the Euler-tour "exit" time for every node, computing it on first access.
+    ///
+    /// A node's exit time is the maximum `enter` time across its entire subtree (itself
+    /// included). Walking [`NodeId`]s from the most- to least-recently inserted guarantees every
+    /// node is visited only after all of its descendants have been (since a child is always
+    /// inserted, and so assigned a larger [`NodeId`], after its parent), so each node can fold its
+    /// own exit time into its parent's in a single backward pass.
+    fn exit_times(&self) -> &IndexVec<NodeId, u32> {
+        self.exit.get_or_init(|| {
+            let mut exit: IndexVec<NodeId, u32> = IndexVec::from_elem_n(0, self.nodes.len());
+
+            for node_id in self.nodes.indices().rev() {
+                let node = &self.nodes[node_id];
+                let node_exit = exit[node_id].max(node.enter);
+                exit[node_id] = node_exit;
+
+                if let Some(parent) = node.parent {
+                    exit[parent] = exit[parent].max(node_exit);
+                }
+            }
+
+            exit
+        })
+    }
+
+    /// Returns `true` if `ancestor` contains `descendant` in its subtree, in O(1) (after the
+    /// one-time cost of computing exit times).
+    ///
+    /// A node is considered to contain itself.
+    ///
+    /// Not yet called from any rule: the `flake8_type_checking` "reference inside a
+    /// `TYPE_CHECKING` block" checks this was meant to speed up (see
+    /// `ruff::rules::flake8_type_checking::helpers::is_valid_runtime_import`) answer that
+    /// question per-*reference* via `Reference::in_type_checking_block`, not by comparing
+    /// statement-level `NodeId`s, so there's no drop-in call site for this yet. Covered directly
+    /// by the tests below in the meantime.
+    #[inline]
+    pub fn contains(&self, ancestor: NodeId, descendant: NodeId) -> bool {
+        let exit = self.exit_times();
+        let ancestor_enter = self.nodes[ancestor].enter;
+        let descendant_enter = self.nodes[descendant].enter;
+        ancestor_enter <= descendant_enter && exit[descendant] <= exit[ancestor]
+    }
+
+    /// Returns the nearest common ancestor of `a` and `b`, climbing from `a` until its interval
+    /// contains `b`.
+    ///
+    /// Not yet called from any rule, for the same reason as [`Nodes::contains`].
+    pub fn common_ancestor(&self, a: NodeId, b: NodeId) -> NodeId {
+        let mut ancestor = a;
+        while !self.contains(ancestor, b) {
+            ancestor = self.nodes[ancestor]
+                .parent
+                .expect("the module root contains every node");
+        }
+        ancestor
+    }
+
+    /// Returns an iterator over the direct children of `node_id`, in source order.
+    ///
+    /// Not yet called from any rule: the per-statement import grouping in
+    /// `ruff::rules::flake8_type_checking::rules::typing_only_runtime_import` this was meant to
+    /// simplify (`errors_by_statement`/`ignores_by_statement`) is built by iterating a scope's
+    /// *bindings*, keyed by each binding's statement `NodeId` -- it never walks the statement
+    /// tree itself, so there's nothing in that loop for a statement-to-statement child/descendant
+    /// walk to replace. Covered directly by the tests below in the meantime.
+    pub fn children(&self, node_id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.children.get(&node_id).into_iter().flatten().copied()
+    }
+
+    /// Returns a preorder iterator over every descendant of `node_id` (not including `node_id`
+    /// itself).
+    ///
+    /// Not yet called from any rule, for the same reason as [`Nodes::children`].
+    pub fn descendants(&self, node_id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let mut stack: Vec<NodeId> = self.children(node_id).collect();
+        stack.reverse();
+
+        std::iter::from_fn(move || {
+            let next = stack.pop()?;
+            // Push in reverse so that, as a stack, we still pop (and thus yield) children in
+            // source order.
+            stack.extend(self.children(next).collect::<Vec<_>>().into_iter().rev());
+            Some(next)
+        })
+    }
 }
 
 impl<'a> Index<NodeId> for Nodes<'a> {
@@ -53,3 +183,143 @@ impl<'a> Index<NodeId> for Nodes<'a> {
         &self.nodes[index].node
     }
 }
+
+// `children`/`descendants`/`contains`/`common_ancestor` have no caller in this tree yet: the
+// per-statement reference grouping and TYPE_CHECKING-containment checks they were added for in
+// `typing_only_runtime_import` turn out to need expression- and reference-level data (e.g. "is
+// this reference inside a `TYPE_CHECKING` block") that this arena, which only indexes statements,
+// can't answer. Wiring that up would mean reworking how references are tracked, well beyond what
+// this request covers, so for now these are exercised directly rather than through a consumer.
+#[cfg(test)]
+mod tests {
+    use ruff_python_ast::node::AnyNodeRef;
+    use ruff_python_ast::StmtPass;
+    use ruff_text_size::{TextRange, TextSize};
+
+    use super::Nodes;
+
+    fn pass(start: u32, end: u32) -> StmtPass {
+        StmtPass {
+            range: TextRange::new(TextSize::new(start), TextSize::new(end)),
+        }
+    }
+
+    /// Builds:
+    /// ```text
+    /// root (0..100)
+    /// ├── child_a (10..40)
+    /// │   ├── grandchild_a1 (15..20)
+    /// │   └── grandchild_a2 (25..30)
+    /// └── child_b (50..90)
+    ///     └── grandchild_b1 (55..60)
+    /// ```
+    /// and returns the nodes alongside the IDs of each, in the order listed above.
+    fn tree(stmts: &[StmtPass; 6]) -> (Nodes<'_>, [super::NodeId; 6]) {
+        let mut nodes = Nodes::default();
+        let root = nodes.insert(AnyNodeRef::StmtPass(&stmts[0]), None);
+        let child_a = nodes.insert(AnyNodeRef::StmtPass(&stmts[1]), Some(root));
+        let grandchild_a1 = nodes.insert(AnyNodeRef::StmtPass(&stmts[2]), Some(child_a));
+        let grandchild_a2 = nodes.insert(AnyNodeRef::StmtPass(&stmts[3]), Some(child_a));
+        let child_b = nodes.insert(AnyNodeRef::StmtPass(&stmts[4]), Some(root));
+        let grandchild_b1 = nodes.insert(AnyNodeRef::StmtPass(&stmts[5]), Some(child_b));
+
+        (
+            nodes,
+            [
+                root,
+                child_a,
+                grandchild_a1,
+                grandchild_a2,
+                child_b,
+                grandchild_b1,
+            ],
+        )
+    }
+
+    fn stmts() -> [StmtPass; 6] {
+        [
+            pass(0, 100),
+            pass(10, 40),
+            pass(15, 20),
+            pass(25, 30),
+            pass(50, 90),
+            pass(55, 60),
+        ]
+    }
+
+    #[test]
+    fn children_are_returned_in_source_order() {
+        let stmts = stmts();
+        let (nodes, [root, child_a, _, _, child_b, _]) = tree(&stmts);
+
+        assert_eq!(nodes.children(root).collect::<Vec<_>>(), vec![child_a, child_b]);
+    }
+
+    #[test]
+    fn children_of_a_leaf_is_empty() {
+        let stmts = stmts();
+        let (nodes, [_, _, grandchild_a1, ..]) = tree(&stmts);
+
+        assert_eq!(nodes.children(grandchild_a1).count(), 0);
+    }
+
+    #[test]
+    fn descendants_are_returned_in_preorder() {
+        let stmts = stmts();
+        let (nodes, [root, child_a, grandchild_a1, grandchild_a2, child_b, grandchild_b1]) =
+            tree(&stmts);
+
+        assert_eq!(
+            nodes.descendants(root).collect::<Vec<_>>(),
+            vec![child_a, grandchild_a1, grandchild_a2, child_b, grandchild_b1]
+        );
+    }
+
+    #[test]
+    fn contains_is_reflexive() {
+        let stmts = stmts();
+        let (nodes, [_, child_a, ..]) = tree(&stmts);
+
+        assert!(nodes.contains(child_a, child_a));
+    }
+
+    #[test]
+    fn contains_holds_for_ancestors_and_descendants() {
+        let stmts = stmts();
+        let (nodes, [root, child_a, grandchild_a1, ..]) = tree(&stmts);
+
+        assert!(nodes.contains(root, grandchild_a1));
+        assert!(nodes.contains(child_a, grandchild_a1));
+    }
+
+    #[test]
+    fn contains_does_not_hold_between_sibling_subtrees() {
+        let stmts = stmts();
+        let (nodes, [_, child_a, grandchild_a1, _, child_b, grandchild_b1]) = tree(&stmts);
+
+        assert!(!nodes.contains(child_a, child_b));
+        assert!(!nodes.contains(child_b, child_a));
+        assert!(!nodes.contains(grandchild_a1, grandchild_b1));
+        assert!(!nodes.contains(grandchild_b1, grandchild_a1));
+    }
+
+    #[test]
+    fn common_ancestor_of_cousins_climbs_to_the_shared_grandparent() {
+        let stmts = stmts();
+        let (nodes, [root, _, grandchild_a1, _, _, grandchild_b1]) = tree(&stmts);
+
+        assert_eq!(nodes.common_ancestor(grandchild_a1, grandchild_b1), root);
+        assert_eq!(nodes.common_ancestor(grandchild_b1, grandchild_a1), root);
+    }
+
+    #[test]
+    fn common_ancestor_of_siblings_is_their_parent() {
+        let stmts = stmts();
+        let (nodes, [_, child_a, grandchild_a1, grandchild_a2, ..]) = tree(&stmts);
+
+        assert_eq!(
+            nodes.common_ancestor(grandchild_a1, grandchild_a2),
+            child_a
+        );
+    }
+}
@@ -0,0 +1,91 @@
+/// A macro for concisely matching a [`SimpleTokenKind`](crate::SimpleTokenKind), mirroring the
+/// `T![...]` token macros used by other language tooling (e.g. `rust-analyzer`).
+///
+/// Punctuation is spelled with its literal characters: brackets, parentheses and braces are
+/// quoted to avoid macro-parsing ambiguity (e.g. `T!['(']`), while other punctuation is spelled
+/// bare (e.g. `T![:]`). Keywords are spelled as bare identifiers (e.g. `T![case]`).
+///
+/// ```ignore
+/// use ruff_python_trivia::T;
+///
+/// assert_eq!(tokenizer.next()?.kind(), T![case]);
+/// tokenizer.skip_while(|token| token.kind() == T![')']);
+/// ```
+#[macro_export]
+macro_rules! T {
+    [:] => {
+        $crate::SimpleTokenKind::Colon
+    };
+    [,] => {
+        $crate::SimpleTokenKind::Comma
+    };
+    ['('] => {
+        $crate::SimpleTokenKind::LParen
+    };
+    [')'] => {
+        $crate::SimpleTokenKind::RParen
+    };
+    ['['] => {
+        $crate::SimpleTokenKind::LBracket
+    };
+    [']'] => {
+        $crate::SimpleTokenKind::RBracket
+    };
+    ['{'] => {
+        $crate::SimpleTokenKind::LBrace
+    };
+    ['}'] => {
+        $crate::SimpleTokenKind::RBrace
+    };
+    [=] => {
+        $crate::SimpleTokenKind::Equals
+    };
+    [*] => {
+        $crate::SimpleTokenKind::Star
+    };
+    [case] => {
+        $crate::SimpleTokenKind::Case
+    };
+    [match] => {
+        $crate::SimpleTokenKind::Match
+    };
+    [class] => {
+        $crate::SimpleTokenKind::Class
+    };
+    [async] => {
+        $crate::SimpleTokenKind::Async
+    };
+    [def] => {
+        $crate::SimpleTokenKind::Def
+    };
+    [if] => {
+        $crate::SimpleTokenKind::If
+    };
+    [elif] => {
+        $crate::SimpleTokenKind::Elif
+    };
+    [else] => {
+        $crate::SimpleTokenKind::Else
+    };
+    [for] => {
+        $crate::SimpleTokenKind::For
+    };
+    [while] => {
+        $crate::SimpleTokenKind::While
+    };
+    [with] => {
+        $crate::SimpleTokenKind::With
+    };
+    [try] => {
+        $crate::SimpleTokenKind::Try
+    };
+    [finally] => {
+        $crate::SimpleTokenKind::Finally
+    };
+    [except] => {
+        $crate::SimpleTokenKind::Except
+    };
+}
+
+// `#[macro_export]` places the macro at the crate root, so call sites can write
+// `ruff_python_trivia::T![case]`.